@@ -0,0 +1,62 @@
+use anyhow::{anyhow, Result};
+use serenity::model::prelude::User;
+
+use crate::helpers::{types::MessageCommandData, utils::parse_target_user};
+
+/// Expressions longer than this are rejected before evaluation, to guard
+/// against pathological input (e.g. deeply nested parentheses).
+const MAX_EXPR_LEN: usize = 256;
+
+/// Replies with the avatar of the mentioned user, or the message author's
+/// avatar if no user was mentioned.
+///
+/// # Arguments
+/// * `data` - The message command data.
+///
+/// # Errors
+/// * If the target user cannot be resolved.
+/// * If sending the reply fails.
+pub async fn user_avatar(data: MessageCommandData<'_>) -> Result<()> {
+    let user = parse_target_user(&data, 1).await?;
+    data.msg.channel_id.say(&data.ctx, avatar_url(&user)).await?;
+
+    Ok(())
+}
+
+/// Returns `user`'s avatar URL, falling back to their default avatar.
+///
+/// Shared between the `pfp` prefix command and the `/pfp` slash command so
+/// the two paths don't diverge.
+pub fn avatar_url(user: &User) -> String {
+    user.avatar_url()
+        .unwrap_or_else(|| user.default_avatar_url())
+}
+
+/// Evaluates an arithmetic expression from the rest of the message, e.g.
+/// `h!calc sqrt(2) * (3 + 4)^2`. Supports `+ - * / ^`, parentheses, and
+/// common functions like `sqrt`, `sin`, `cos`, `ln`, `abs`.
+///
+/// # Errors
+/// * If no expression was given.
+/// * If the expression is too long.
+/// * If the expression fails to parse or evaluate.
+pub async fn calculate(data: MessageCommandData<'_>) -> Result<()> {
+    let expression = data.content.get(1..).unwrap_or_default().join(" ");
+    if expression.is_empty() {
+        return Err(anyhow!("Please provide an expression to evaluate"));
+    }
+    if expression.len() > MAX_EXPR_LEN {
+        return Err(anyhow!("That expression is too long"));
+    }
+
+    // Parse/eval failures are a user typo, not a bot bug, so reply directly
+    // instead of bubbling the error up to the error-log channel.
+    let reply = match meval::eval_str(&expression) {
+        Ok(result) => result.to_string(),
+        Err(_) => format!("Couldn't evaluate `{expression}`, check your syntax"),
+    };
+
+    data.msg.channel_id.say(&data.ctx, reply).await?;
+
+    Ok(())
+}
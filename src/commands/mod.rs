@@ -0,0 +1,5 @@
+pub mod misc;
+pub mod music;
+pub mod reminders;
+pub mod status;
+pub mod text;
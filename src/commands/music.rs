@@ -0,0 +1,183 @@
+use anyhow::{anyhow, Result};
+use songbird::input::restartable::Restartable;
+
+use crate::helpers::{types::MessageCommandData, utils::raw_rest_of_message};
+
+/// Joins the voice channel the message author is currently in.
+///
+/// # Errors
+/// * If the author is not in a voice channel.
+/// * If songbird fails to join the channel.
+pub async fn join(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    let channel_id = data
+        .msg
+        .guild(&data.ctx.cache)
+        .and_then(|guild| {
+            guild
+                .voice_states
+                .get(&data.msg.author.id)
+                .and_then(|state| state.channel_id)
+        })
+        .ok_or_else(|| anyhow!("You need to be in a voice channel first"))?;
+
+    data.handler
+        .songbird
+        .join(guild_id, channel_id)
+        .await
+        .1
+        .map_err(|_| anyhow!("Failed to join the voice channel"))?;
+
+    data.msg
+        .channel_id
+        .say(&data.ctx, format!("Joined <#{channel_id}>"))
+        .await?;
+
+    Ok(())
+}
+
+/// Leaves the current voice channel and clears the guild's track queue.
+///
+/// # Errors
+/// * If the bot is not currently connected to a voice channel.
+pub async fn leave(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    data.handler
+        .songbird
+        .remove(guild_id)
+        .await
+        .map_err(|_| anyhow!("Not currently in a voice channel"))?;
+
+    data.msg.channel_id.say(&data.ctx, "Left the voice channel").await?;
+
+    Ok(())
+}
+
+/// Streams audio from a URL or search term into the voice channel the bot
+/// is connected to, queueing it if something is already playing.
+///
+/// # Errors
+/// * If the bot is not in a voice channel.
+/// * If no search term/URL was given.
+/// * If resolving or starting the audio source fails.
+pub async fn play(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    // Read from the raw message, not the lowercased `data.content` - video
+    // IDs and URL paths are case-sensitive.
+    let query = raw_rest_of_message(&data.msg.content, 1);
+    if query.is_empty() {
+        return Err(anyhow!("Please provide a URL or search term"));
+    }
+
+    let call = data
+        .handler
+        .songbird
+        .get(guild_id)
+        .ok_or_else(|| anyhow!("I need to be in a voice channel first, use `join`"))?;
+
+    let source = if query.starts_with("http") {
+        Restartable::ytdl(query, false).await
+    } else {
+        Restartable::ytdl_search(query, false).await
+    }
+    .map_err(|_| anyhow!("Failed to resolve that source"))?;
+
+    call.lock().await.enqueue_source(source.into());
+
+    data.msg.channel_id.say(&data.ctx, "Added to the queue").await?;
+
+    Ok(())
+}
+
+/// Skips the currently playing track, advancing to the next queued one.
+///
+/// # Errors
+/// * If the bot is not in a voice channel.
+pub async fn skip(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    let call = data
+        .handler
+        .songbird
+        .get(guild_id)
+        .ok_or_else(|| anyhow!("I'm not in a voice channel"))?;
+
+    call.lock()
+        .await
+        .queue()
+        .skip()
+        .map_err(|_| anyhow!("Failed to skip the current track"))?;
+
+    data.msg.channel_id.say(&data.ctx, "Skipped").await?;
+
+    Ok(())
+}
+
+/// Stops playback and clears the guild's track queue without leaving the
+/// voice channel.
+///
+/// # Errors
+/// * If the bot is not in a voice channel.
+pub async fn stop(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    let call = data
+        .handler
+        .songbird
+        .get(guild_id)
+        .ok_or_else(|| anyhow!("I'm not in a voice channel"))?;
+
+    call.lock().await.queue().stop();
+
+    data.msg.channel_id.say(&data.ctx, "Stopped playback").await?;
+
+    Ok(())
+}
+
+/// Replies with the number of tracks currently queued for the guild.
+///
+/// # Errors
+/// * If the bot is not in a voice channel.
+/// * If sending the reply fails.
+pub async fn queue(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    let call = data
+        .handler
+        .songbird
+        .get(guild_id)
+        .ok_or_else(|| anyhow!("I'm not in a voice channel"))?;
+
+    let queued = call.lock().await.queue().len();
+
+    let reply = if queued == 0 {
+        "The queue is empty".to_string()
+    } else {
+        format!("{queued} track(s) queued")
+    };
+
+    data.msg.channel_id.say(&data.ctx, reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use std::time::Duration as StdDuration;
+
+use anyhow::{anyhow, Result};
+use bson::oid::ObjectId;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+
+use crate::helpers::{
+    types::{MessageCommandData, ReminderDoc},
+    utils::raw_rest_of_message,
+};
+
+/// Parses `h!remind <time> <message>`, storing the reminder in the
+/// `reminders` collection so `start_reminder_loop` can deliver it later.
+///
+/// `<time>` is either a relative duration such as `10m` or `2h30m`, or an
+/// absolute `YYYY-MM-DD HH:MM` pair.
+///
+/// # Errors
+/// * If no time or message was given.
+/// * If the time could not be parsed.
+/// * If inserting the reminder fails.
+pub async fn remind(data: MessageCommandData<'_>) -> Result<()> {
+    let time_spec = data
+        .content
+        .get(1)
+        .ok_or_else(|| anyhow!("Usage: `remind <time> <message>`, e.g. `remind 10m take out the trash`"))?;
+
+    let (fire_at, message_start_idx) = if looks_like_date(time_spec) {
+        let time_part = data
+            .content
+            .get(2)
+            .ok_or_else(|| anyhow!("Expected a time after the date, e.g. `18:00`"))?;
+
+        let fire_at = parse_absolute_time(time_spec, time_part)
+            .ok_or_else(|| anyhow!("Could not parse that date/time, expected `YYYY-MM-DD HH:MM`"))?;
+
+        (fire_at, 3)
+    } else {
+        let duration = parse_relative_duration(time_spec).ok_or_else(|| {
+            anyhow!("Could not parse that duration, try something like `10m` or `2h30m`")
+        })?;
+
+        let fire_at = Utc::now()
+            + Duration::from_std(duration).map_err(|_| anyhow!("That duration is too long"))?;
+
+        (fire_at, 2)
+    };
+
+    let message = raw_rest_of_message(&data.msg.content, message_start_idx);
+    if message.is_empty() {
+        return Err(anyhow!("Please include a reminder message"));
+    }
+
+    let reminder = ReminderDoc {
+        _id: ObjectId::new(),
+        user_id: data.msg.author.id.to_string(),
+        channel_id: data.msg.channel_id.to_string(),
+        fire_at,
+        message,
+    };
+
+    data.handler
+        .db_client
+        .database("hifumi")
+        .collection::<ReminderDoc>("reminders")
+        .insert_one(&reminder, None)
+        .await?;
+
+    data.msg
+        .channel_id
+        .say(
+            &data.ctx,
+            format!("Got it, I'll remind you <t:{}:R>", fire_at.timestamp()),
+        )
+        .await?;
+
+    Ok(())
+}
+
+fn looks_like_date(spec: &str) -> bool {
+    NaiveDate::parse_from_str(spec, "%Y-%m-%d").is_ok()
+}
+
+/// Parses a `YYYY-MM-DD` date and an `HH:MM` time into a UTC timestamp.
+///
+/// # Examples
+/// ```
+/// let fire_at = parse_absolute_time("2026-01-01", "18:00").unwrap();
+/// assert_eq!(fire_at.to_string(), "2026-01-01 18:00:00 UTC");
+///
+/// assert!(parse_absolute_time("not-a-date", "18:00").is_none());
+/// ```
+fn parse_absolute_time(date: &str, time: &str) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+
+    Some(DateTime::from_naive_utc_and_offset(date.and_time(time), Utc))
+}
+
+/// Scans `spec` for `number+unit` pairs (`w`, `d`, `h`, `m`, `s`), summing
+/// each into a total duration. Returns `None` if no valid unit was found,
+/// e.g. for `spec`s like `10` or `abc`, or if the total would overflow.
+///
+/// # Examples
+/// ```
+/// assert_eq!(parse_relative_duration("2h30m").unwrap(), StdDuration::from_secs(2 * 60 * 60 + 30 * 60));
+/// assert!(parse_relative_duration("abc").is_none());
+/// assert!(parse_relative_duration("99999999999999999w").is_none());
+/// ```
+fn parse_relative_duration(spec: &str) -> Option<StdDuration> {
+    let mut total = StdDuration::ZERO;
+    let mut digits = String::new();
+    let mut found_unit = false;
+
+    for ch in spec.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+            continue;
+        }
+
+        let amount: u64 = digits.parse().ok()?;
+        digits.clear();
+
+        let unit_seconds = match ch {
+            'w' => 7 * 24 * 60 * 60,
+            'd' => 24 * 60 * 60,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+
+        let secs = amount.checked_mul(unit_seconds)?;
+        total = total.checked_add(StdDuration::from_secs(secs))?;
+        found_unit = true;
+    }
+
+    found_unit.then_some(total)
+}
@@ -0,0 +1,153 @@
+use anyhow::{anyhow, Result};
+use bson::{doc, oid::ObjectId};
+
+use crate::helpers::{
+    types::{MessageCommandData, StatusDoc},
+    utils::{is_bot_owner, raw_rest_of_message},
+};
+
+const KNOWN_STATUS_TYPES: &[&str] = &["playing", "watching", "listening", "competing", "streaming"];
+
+/// Adds a new status to the `statuses` collection and to the in-memory
+/// list the status loop picks from, so it takes effect without a restart.
+/// The status loop stops once it finds the list empty, so a status added
+/// after that is not shown until the bot restarts.
+///
+/// `type` must be one of `PLAYING`, `WATCHING`, `LISTENING`, `COMPETING`,
+/// or `STREAMING` (which additionally requires a URL as the next word).
+///
+/// # Errors
+/// * If the author isn't a bot owner.
+/// * If the type is missing, unknown, or `STREAMING` is missing its URL.
+/// * If inserting into the database fails.
+pub async fn addstatus(data: MessageCommandData<'_>) -> Result<()> {
+    require_owner(&data)?;
+
+    let r#type = data
+        .content
+        .get(1)
+        .ok_or_else(|| anyhow!("Usage: `addstatus <type> <text>`"))?
+        .to_lowercase();
+
+    if !KNOWN_STATUS_TYPES.contains(&r#type.as_str()) {
+        return Err(anyhow!(
+            "Unknown status type `{type}`, expected one of {KNOWN_STATUS_TYPES:?}"
+        ));
+    }
+
+    // Read from the raw message, not the lowercased `data.content` - the
+    // status text and stream URL should keep the author's original casing.
+    let rest = raw_rest_of_message(&data.msg.content, 2);
+
+    let (status, url) = if r#type == "streaming" {
+        let mut parts = rest.splitn(2, char::is_whitespace);
+        let url = parts
+            .next()
+            .filter(|token| token.starts_with("http"))
+            .ok_or_else(|| anyhow!("`STREAMING` statuses need a URL as the first word"))?
+            .to_string();
+        (parts.next().unwrap_or_default().trim_start().to_string(), Some(url))
+    } else {
+        (rest, None)
+    };
+
+    if status.is_empty() {
+        return Err(anyhow!("Please provide the status text"));
+    }
+
+    let status_doc = StatusDoc {
+        _id: ObjectId::new(),
+        r#type: r#type.to_uppercase(),
+        status,
+        url,
+    };
+
+    data.handler
+        .db_client
+        .database("hifumi")
+        .collection::<StatusDoc>("statuses")
+        .insert_one(&status_doc, None)
+        .await?;
+
+    data.handler.statuses.write().await.push(status_doc);
+
+    data.msg.channel_id.say(&data.ctx, "Added the new status").await?;
+
+    Ok(())
+}
+
+/// Removes the status at `index` (as shown by `liststatus`) from both the
+/// database and the in-memory list.
+///
+/// # Errors
+/// * If the author isn't a bot owner.
+/// * If no valid index was given, or nothing is configured at it.
+pub async fn delstatus(data: MessageCommandData<'_>) -> Result<()> {
+    require_owner(&data)?;
+
+    let index = data
+        .content
+        .get(1)
+        .and_then(|s| s.parse::<usize>().ok())
+        .ok_or_else(|| anyhow!("Usage: `delstatus <index>`, see `liststatus` for indices"))?;
+
+    let mut statuses = data.handler.statuses.write().await;
+    if index >= statuses.len() {
+        return Err(anyhow!("No status at index {index}"));
+    }
+
+    let removed = statuses.remove(index);
+
+    data.handler
+        .db_client
+        .database("hifumi")
+        .collection::<StatusDoc>("statuses")
+        .delete_one(doc! { "_id": removed._id }, None)
+        .await?;
+
+    data.msg
+        .channel_id
+        .say(
+            &data.ctx,
+            format!("Removed status: {} {}", removed.r#type, removed.status),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lists the currently configured statuses along with the index
+/// `delstatus` expects.
+///
+/// # Errors
+/// * If the author isn't a bot owner.
+/// * If sending the reply fails.
+pub async fn liststatus(data: MessageCommandData<'_>) -> Result<()> {
+    require_owner(&data)?;
+
+    let statuses = data.handler.statuses.read().await;
+    let list = statuses
+        .iter()
+        .enumerate()
+        .map(|(i, s)| format!("{i}. {} {}", s.r#type, s.status))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let reply = if list.is_empty() {
+        "No statuses configured".to_string()
+    } else {
+        list
+    };
+
+    data.msg.channel_id.say(&data.ctx, reply).await?;
+
+    Ok(())
+}
+
+fn require_owner(data: &MessageCommandData<'_>) -> Result<()> {
+    if is_bot_owner(&data.handler.config, data.msg.author.id) {
+        Ok(())
+    } else {
+        Err(anyhow!("This command is owner-only"))
+    }
+}
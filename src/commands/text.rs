@@ -0,0 +1,167 @@
+use anyhow::{anyhow, Result};
+
+use crate::helpers::{
+    types::MessageCommandData,
+    utils::{random_element_vec, raw_rest_of_message},
+};
+
+const MAX_OUTPUT_LEN: usize = 512;
+
+const KAOMOJI: &[&str] = &["(◕‿◕✿)", "(ᵘʷᵘ)", "uwu", "owo", ">w<", "(,,>ω<,,)", "nyaa~"];
+
+/// Replies with `owo`-ified text, see [`owoify`].
+///
+/// # Errors
+/// * If the rest of the message is empty.
+/// * If the transformed text is too long.
+pub async fn owo(data: MessageCommandData<'_>) -> Result<()> {
+    let transformed = owoify(&rest_of_message(&data)?)?;
+    data.msg.channel_id.say(&data.ctx, transformed).await?;
+    Ok(())
+}
+
+/// Replies with leetspeak text, see [`leetify`].
+///
+/// # Errors
+/// * If the rest of the message is empty.
+/// * If the transformed text is too long.
+pub async fn leet(data: MessageCommandData<'_>) -> Result<()> {
+    let transformed = leetify(&rest_of_message(&data)?)?;
+    data.msg.channel_id.say(&data.ctx, transformed).await?;
+    Ok(())
+}
+
+/// Replies with mocking sPoNgEbOb text, see [`mockify`].
+///
+/// # Errors
+/// * If the rest of the message is empty.
+/// * If the transformed text is too long.
+pub async fn mock(data: MessageCommandData<'_>) -> Result<()> {
+    let transformed = mockify(&rest_of_message(&data)?)?;
+    data.msg.channel_id.say(&data.ctx, transformed).await?;
+    Ok(())
+}
+
+fn rest_of_message(data: &MessageCommandData<'_>) -> Result<String> {
+    // Read from the raw message, not the lowercased `data.content` - these
+    // commands need to preserve the user's original casing.
+    let text = raw_rest_of_message(&data.msg.content, 1);
+    if text.is_empty() {
+        return Err(anyhow!("Please provide some text"));
+    }
+    Ok(text)
+}
+
+/// Converts `input` to "owo" speak: swaps `r`/`l` for `w`, inserts a `y`
+/// after an `n` that's followed by a vowel, and appends a randomly chosen
+/// kaomoji from [`KAOMOJI`] if it still fits. No stutter is added.
+///
+/// # Examples
+/// ```
+/// let owoified = owoify("hello").unwrap();
+/// assert!(owoified.starts_with("hewwo"));
+/// ```
+///
+/// # Errors
+/// * If the transformed text would exceed [`MAX_OUTPUT_LEN`] bytes.
+pub fn owoify(input: &str) -> Result<String> {
+    let mut out = String::with_capacity(MAX_OUTPUT_LEN);
+    let mut chars = input.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            'r' | 'l' => out.push('w'),
+            'R' | 'L' => out.push('W'),
+            'n' | 'N' if matches!(chars.peek(), Some(c) if "aeiouAEIOU".contains(*c)) => {
+                out.push(ch);
+                out.push(if ch.is_uppercase() { 'Y' } else { 'y' });
+            }
+            _ => out.push(ch),
+        }
+
+        if out.len() > MAX_OUTPUT_LEN {
+            return Err(anyhow!("That message is too long to owoify"));
+        }
+    }
+
+    if let Some(kaomoji) = random_element_vec(KAOMOJI) {
+        if out.len() + kaomoji.len() + 1 <= MAX_OUTPUT_LEN {
+            out.push(' ');
+            out.push_str(kaomoji);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Converts `input` to leetspeak: `a`\u{2192}`4`, `e`\u{2192}`3`, `i`\u{2192}`1`,
+/// `o`\u{2192}`0`, `t`\u{2192}`7`, `s`\u{2192}`5`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(leetify("leet").unwrap(), "l337");
+/// ```
+///
+/// # Errors
+/// * If the transformed text would exceed [`MAX_OUTPUT_LEN`] bytes.
+pub fn leetify(input: &str) -> Result<String> {
+    let mut out = String::with_capacity(MAX_OUTPUT_LEN);
+
+    for ch in input.chars() {
+        if out.len() >= MAX_OUTPUT_LEN {
+            return Err(anyhow!("That message is too long to leetify"));
+        }
+
+        let mapped = match ch.to_ascii_lowercase() {
+            'a' => '4',
+            'e' => '3',
+            'i' => '1',
+            'o' => '0',
+            't' => '7',
+            's' => '5',
+            _ => ch,
+        };
+
+        out.push(mapped);
+
+        if out.len() > MAX_OUTPUT_LEN {
+            return Err(anyhow!("That message is too long to leetify"));
+        }
+    }
+
+    Ok(out)
+}
+
+/// Converts `input` to mOcKiNg text by alternating upper/lower case on
+/// each alphabetic character.
+///
+/// # Examples
+/// ```
+/// assert_eq!(mockify("hello").unwrap(), "HeLlO");
+/// ```
+///
+/// # Errors
+/// * If the transformed text would exceed [`MAX_OUTPUT_LEN`] bytes.
+pub fn mockify(input: &str) -> Result<String> {
+    let mut out = String::with_capacity(MAX_OUTPUT_LEN);
+    let mut upper = true;
+
+    for ch in input.chars() {
+        if ch.is_alphabetic() {
+            out.push(if upper {
+                ch.to_ascii_uppercase()
+            } else {
+                ch.to_ascii_lowercase()
+            });
+            upper = !upper;
+        } else {
+            out.push(ch);
+        }
+
+        if out.len() > MAX_OUTPUT_LEN {
+            return Err(anyhow!("That message is too long to mock"));
+        }
+    }
+
+    Ok(out)
+}
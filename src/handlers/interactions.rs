@@ -0,0 +1,101 @@
+use anyhow::Result;
+use serenity::{
+    all::{
+        ButtonStyle, Command, CommandInteraction, ComponentInteraction, Context, CreateActionRow,
+        CreateButton, CreateCommand, CreateInteractionResponse, CreateInteractionResponseMessage,
+        Interaction,
+    },
+    model::prelude::*,
+};
+
+use crate::commands::misc::avatar_url;
+
+const CONFIRM_ID: &str = "confirm";
+const CANCEL_ID: &str = "cancel";
+
+/// Registers the bot's global slash commands.
+///
+/// Called once on `ready` so commands like `ping` and `pfp` can be invoked
+/// with `/` in addition to the usual prefix.
+///
+/// # Errors
+/// * If Discord rejects the command registration.
+pub async fn register_commands(ctx: &Context) -> Result<()> {
+    Command::set_global_commands(
+        &ctx.http,
+        vec![
+            CreateCommand::new("ping").description("Replies with Pong!"),
+            CreateCommand::new("pfp").description("Shows your avatar"),
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Entry point for `Interaction::Command` and `Interaction::Component`
+/// events, dispatching to the matching handler below.
+///
+/// # Arguments
+/// * `ctx` - The context of the interaction.
+/// * `interaction` - The interaction to handle.
+///
+/// # Errors
+/// * If the interaction cannot be parsed or responded to.
+pub async fn handle_interaction_create(ctx: &Context, interaction: Interaction) -> Result<()> {
+    match interaction {
+        Interaction::Command(command) => handle_command_interaction(ctx, command).await,
+        Interaction::Component(component) => handle_component_interaction(ctx, component).await,
+        _ => Ok(()),
+    }
+}
+
+async fn handle_command_interaction(ctx: &Context, command: CommandInteraction) -> Result<()> {
+    let response = match command.data.name.as_str() {
+        "ping" => CreateInteractionResponseMessage::new().content("Pong!"),
+        "pfp" => CreateInteractionResponseMessage::new()
+            .content("Show your avatar?")
+            .components(vec![confirm_cancel_row()]),
+        _ => return Ok(()),
+    };
+
+    command
+        .create_response(&ctx.http, CreateInteractionResponse::Message(response))
+        .await?;
+
+    Ok(())
+}
+
+async fn handle_component_interaction(ctx: &Context, component: ComponentInteraction) -> Result<()> {
+    let content = match component.data.custom_id.as_str() {
+        CONFIRM_ID => avatar_url(&component.user),
+        CANCEL_ID => "Cancelled.".to_string(),
+        _ => return Ok(()),
+    };
+
+    component
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .components(vec![]),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Builds a confirm/cancel action row, shared by any command that needs to
+/// ask for confirmation before proceeding.
+fn confirm_cancel_row() -> CreateActionRow {
+    CreateActionRow::Buttons(vec![
+        CreateButton::new(CONFIRM_ID)
+            .label("Confirm")
+            .style(ButtonStyle::Success),
+        CreateButton::new(CANCEL_ID)
+            .label("Cancel")
+            .style(ButtonStyle::Danger),
+    ])
+}
@@ -3,7 +3,14 @@ use serenity::model::prelude::*;
 use serenity::prelude::*;
 
 use crate::{
-    commands::misc::user_avatar,
+    commands::{
+        misc::{calculate, user_avatar},
+        music,
+        reminders::remind,
+        status::{addstatus, delstatus, liststatus},
+        text::{leet, mock, owo},
+    },
+    handlers::moderation::ghostping,
     helpers::{
         types::{Handler, MessageCommandData, PrefixDoc},
         utils::{is_indev, register_prefix},
@@ -93,6 +100,36 @@ async fn handle_command(data: MessageCommandData<'_>) -> Result<()> {
         data.msg.channel_id.say(&data.ctx, "Pong!").await?;
     } else if data.command == "pfp" {
         user_avatar(data).await?;
+    } else if data.command == "join" {
+        music::join(data).await?;
+    } else if data.command == "leave" {
+        music::leave(data).await?;
+    } else if data.command == "play" {
+        music::play(data).await?;
+    } else if data.command == "skip" {
+        music::skip(data).await?;
+    } else if data.command == "stop" {
+        music::stop(data).await?;
+    } else if data.command == "queue" {
+        music::queue(data).await?;
+    } else if data.command == "remind" {
+        remind(data).await?;
+    } else if data.command == "owo" {
+        owo(data).await?;
+    } else if data.command == "leet" {
+        leet(data).await?;
+    } else if data.command == "mock" {
+        mock(data).await?;
+    } else if data.command == "calc" || data.command == "math" {
+        calculate(data).await?;
+    } else if data.command == "addstatus" {
+        addstatus(data).await?;
+    } else if data.command == "delstatus" {
+        delstatus(data).await?;
+    } else if data.command == "liststatus" {
+        liststatus(data).await?;
+    } else if data.command == "ghostping" {
+        ghostping(data).await?;
     }
 
     Ok(())
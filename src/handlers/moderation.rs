@@ -0,0 +1,283 @@
+use anyhow::{anyhow, Result};
+use bson::oid::ObjectId;
+use chrono::{Duration, Utc};
+use mongodb::bson::doc;
+use serenity::{
+    all::{Context, CreateEmbed, CreateMessage, GuildId, MessageUpdateEvent},
+    model::prelude::{ChannelId, Message, MessageId},
+};
+
+use crate::helpers::{
+    types::{CachedMessage, GhostPingDoc, Handler, MessageCommandData},
+    utils::is_bot_owner,
+};
+
+/// Messages are only kept around this long, just enough to cover the usual
+/// gap between sending a ping and someone deleting or editing it.
+const CACHE_TTL: Duration = Duration::minutes(10);
+
+/// Upper bound on the number of cached messages, so a busy server can't
+/// grow the cache without bound between age-based sweeps.
+const MAX_CACHED_MESSAGES: usize = 1000;
+
+/// Caches a message if it pinged a user or role, so a later delete or edit
+/// can be checked for a ghost ping. Also evicts stale entries, and the
+/// oldest entry once the cache is at capacity.
+///
+/// # Errors
+/// * Never returns an error; kept `Result`-returning for symmetry with the
+///   other event handlers it's called alongside.
+pub async fn cache_message(handler: &Handler<'_>, msg: &Message) -> Result<()> {
+    let mut cache = handler.message_cache.write().await;
+
+    let cutoff = Utc::now() - CACHE_TTL;
+    cache.retain(|_, cached| cached.cached_at > cutoff);
+
+    if msg.mentions.is_empty() && msg.mention_roles.is_empty() {
+        return Ok(());
+    }
+
+    if cache.len() >= MAX_CACHED_MESSAGES {
+        if let Some(oldest_id) = cache
+            .iter()
+            .min_by_key(|(_, cached)| cached.cached_at)
+            .map(|(id, _)| *id)
+        {
+            cache.remove(&oldest_id);
+        }
+    }
+
+    cache.insert(
+        msg.id,
+        CachedMessage {
+            content: msg.content.clone(),
+            author_id: msg.author.id,
+            channel_id: msg.channel_id,
+            mentioned_users: msg.mentions.iter().map(|user| user.id).collect(),
+            mentioned_roles: msg.mention_roles.clone(),
+            cached_at: Utc::now(),
+        },
+    );
+
+    Ok(())
+}
+
+/// Detects a ghost ping when a cached message that mentioned someone is
+/// deleted, and reports it to the channel it was sent in.
+///
+/// # Errors
+/// * If checking the per-guild toggle fails.
+/// * If sending the report embed fails.
+pub async fn handle_message_delete(
+    handler: &Handler<'_>,
+    ctx: &Context,
+    channel_id: ChannelId,
+    deleted_message_id: MessageId,
+    guild_id: Option<GuildId>,
+) -> Result<()> {
+    let Some(guild_id) = guild_id else {
+        return Ok(());
+    };
+
+    if !is_ghost_ping_enabled(handler, guild_id.to_string()).await? {
+        return Ok(());
+    }
+
+    let cached = handler.message_cache.write().await.remove(&deleted_message_id);
+
+    if let Some(cached) = cached {
+        if !cached.mentioned_users.is_empty() || !cached.mentioned_roles.is_empty() {
+            report_ghost_ping(ctx, channel_id, &cached).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects a ghost ping when a cached message is edited such that a
+/// mention is removed, then refreshes the cached copy.
+///
+/// # Errors
+/// * If checking the per-guild toggle fails.
+/// * If sending the report embed fails.
+pub async fn handle_message_update(
+    handler: &Handler<'_>,
+    ctx: &Context,
+    event: &MessageUpdateEvent,
+) -> Result<()> {
+    let Some(guild_id) = event.guild_id else {
+        return Ok(());
+    };
+
+    if !is_ghost_ping_enabled(handler, guild_id.to_string()).await? {
+        return Ok(());
+    }
+
+    let Some(cached) = handler.message_cache.read().await.get(&event.id).cloned() else {
+        return Ok(());
+    };
+
+    // `event.mentions`/`event.mention_roles` are only `Some` when the edit
+    // actually carries a new mention set. An update that leaves mentions
+    // untouched (e.g. Discord attaching a link embed) reports `None` here,
+    // and must not be treated as having cleared every mention.
+    if let Some(new_mentions) = &event.mentions {
+        let new_users: Vec<_> = new_mentions.iter().map(|user| user.id).collect();
+        let new_roles = event.mention_roles.clone().unwrap_or_default();
+
+        let lost_a_mention = cached
+            .mentioned_users
+            .iter()
+            .any(|id| !new_users.contains(id))
+            || cached
+                .mentioned_roles
+                .iter()
+                .any(|id| !new_roles.contains(id));
+
+        if lost_a_mention {
+            report_ghost_ping(ctx, cached.channel_id, &cached).await?;
+        }
+
+        if let Some(entry) = handler.message_cache.write().await.get_mut(&event.id) {
+            entry.mentioned_users = new_users;
+            entry.mentioned_roles = new_roles;
+        }
+    }
+
+    if let Some(content) = &event.content {
+        if let Some(entry) = handler.message_cache.write().await.get_mut(&event.id) {
+            entry.content = content.clone();
+        }
+    }
+
+    Ok(())
+}
+
+async fn report_ghost_ping(ctx: &Context, channel_id: ChannelId, cached: &CachedMessage) -> Result<()> {
+    let mentioned = cached
+        .mentioned_users
+        .iter()
+        .map(|id| format!("<@{id}>"))
+        .chain(cached.mentioned_roles.iter().map(|id| format!("<@&{id}>")))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let embed = CreateEmbed::new()
+        .title("Ghost ping detected")
+        .description(format!(
+            "<@{}> pinged {mentioned} and then deleted or edited the message",
+            cached.author_id
+        ))
+        .field("Original content", &cached.content, false);
+
+    channel_id
+        .send_message(&ctx.http, CreateMessage::new().embed(embed))
+        .await?;
+
+    Ok(())
+}
+
+/// Toggles the ghost-ping detector on or off for the current guild,
+/// persisting the choice to the `ghost_ping_config` collection and
+/// refreshing the in-memory cache `is_ghost_ping_enabled` reads from.
+///
+/// # Errors
+/// * If used outside a server.
+/// * If the author isn't a server administrator or bot owner.
+/// * If no `on`/`off` argument was given.
+/// * If updating the database fails.
+pub async fn ghostping(data: MessageCommandData<'_>) -> Result<()> {
+    let guild_id = data
+        .msg
+        .guild_id
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    require_guild_admin(&data)?;
+
+    let enabled = match data.content.get(1).map(String::as_str) {
+        Some("on") => true,
+        Some("off") => false,
+        _ => return Err(anyhow!("Usage: `ghostping <on|off>`")),
+    };
+
+    let server_id = guild_id.to_string();
+
+    let coll = data
+        .handler
+        .db_client
+        .database("hifumi")
+        .collection::<GhostPingDoc>("ghost_ping_config");
+
+    coll.delete_one(doc! { "server_id": &server_id }, None).await?;
+    coll.insert_one(
+        &GhostPingDoc {
+            _id: ObjectId::new(),
+            server_id: server_id.clone(),
+            enabled,
+        },
+        None,
+    )
+    .await?;
+
+    data.handler
+        .ghost_ping_settings
+        .write()
+        .await
+        .insert(server_id, enabled);
+
+    data.msg
+        .channel_id
+        .say(
+            &data.ctx,
+            format!("Ghost ping detection is now {}", if enabled { "on" } else { "off" }),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Requires the author to be a server administrator (or a bot owner).
+fn require_guild_admin(data: &MessageCommandData<'_>) -> Result<()> {
+    if is_bot_owner(&data.handler.config, data.msg.author.id) {
+        return Ok(());
+    }
+
+    let guild = data
+        .msg
+        .guild(&data.ctx.cache)
+        .ok_or_else(|| anyhow!("This command can only be used in a server"))?;
+
+    let is_admin = guild
+        .members
+        .get(&data.msg.author.id)
+        .and_then(|member| member.permissions(&data.ctx.cache).ok())
+        .is_some_and(|permissions| permissions.administrator());
+
+    if is_admin {
+        Ok(())
+    } else {
+        Err(anyhow!("This command requires the Administrator permission"))
+    }
+}
+
+async fn is_ghost_ping_enabled(handler: &Handler<'_>, server_id: String) -> Result<bool> {
+    if let Some(enabled) = handler.ghost_ping_settings.read().await.get(&server_id) {
+        return Ok(*enabled);
+    }
+
+    let coll = handler
+        .db_client
+        .database("hifumi")
+        .collection::<GhostPingDoc>("ghost_ping_config");
+
+    let doc = coll.find_one(doc! { "server_id": &server_id }, None).await?;
+    let enabled = doc.map_or(true, |doc| doc.enabled);
+
+    handler
+        .ghost_ping_settings
+        .write()
+        .await
+        .insert(server_id, enabled);
+
+    Ok(enabled)
+}
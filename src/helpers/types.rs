@@ -3,19 +3,28 @@ use bson::oid::ObjectId;
 use chrono::{DateTime, Utc};
 use mongodb::Client as MongoClient;
 use serde::{Deserialize, Serialize};
-use serenity::{model::prelude::Message, prelude::Context};
-use std::collections::HashMap;
-use tokio::sync::Mutex;
+use serenity::{
+    all::UserId,
+    model::prelude::{ChannelId, Context, Message, MessageId, RoleId},
+};
+use songbird::Songbird;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
 
-pub type StatusVec = Mutex<Vec<StatusDoc>>;
-pub type PrefixMap = Mutex<HashMap<String, String>>;
+pub type StatusVec = RwLock<Vec<StatusDoc>>;
+pub type PrefixMap = RwLock<HashMap<String, String>>;
+pub type GhostPingMap = RwLock<HashMap<String, bool>>;
+pub type MessageCache = RwLock<HashMap<MessageId, CachedMessage>>;
 
-pub struct MessageCommandData {
-    pub ctx: Context,
-    pub msg: Message,
+pub struct MessageCommandData<'a> {
+    pub ctx: &'a Context,
+    pub msg: &'a Message,
+    pub content: Vec<String>,
     pub command: String,
     pub react_cmd: String,
     pub sub_cmd: String,
+    pub handler: &'a Handler<'a>,
+    pub prefix: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -23,20 +32,65 @@ pub struct StatusDoc {
     pub _id: ObjectId,
     pub r#type: String,
     pub status: String,
+    /// Only present (and required) for `STREAMING` statuses.
+    pub url: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[allow(non_snake_case)]
 pub struct PrefixDoc {
     pub _id: ObjectId,
-    pub serverId: String,
+    #[serde(rename = "serverId")]
+    pub server_id: String,
     pub prefix: String,
 }
 
-pub struct Handler {
+/// A reminder to be delivered at `fire_at`, stored in the `reminders`
+/// collection so it survives a restart of the bot.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReminderDoc {
+    pub _id: ObjectId,
+    pub user_id: String,
+    pub channel_id: String,
+    pub fire_at: DateTime<Utc>,
+    pub message: String,
+}
+
+/// Per-guild toggle for the ghost-ping detector, stored in the
+/// `ghost_ping_config` collection analogous to `PrefixDoc`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GhostPingDoc {
+    pub _id: ObjectId,
+    pub server_id: String,
+    pub enabled: bool,
+}
+
+/// A recently seen message, kept just long enough to detect ghost pings
+/// since Discord does not deliver the content of deleted messages.
+#[derive(Debug, Clone)]
+pub struct CachedMessage {
+    pub content: String,
+    pub author_id: UserId,
+    pub channel_id: ChannelId,
+    pub mentioned_users: Vec<UserId>,
+    pub mentioned_roles: Vec<RoleId>,
+    pub cached_at: DateTime<Utc>,
+}
+
+/// The primary bot owner plus any secondary owners who should also pass
+/// owner-only checks.
+#[derive(Debug, Clone)]
+pub struct Owners {
+    pub primary: UserId,
+    pub secondary: Vec<UserId>,
+}
+
+pub struct Handler<'a> {
     pub start_time: DateTime<Utc>,
-    pub config: Config,
+    pub config: Config<'a>,
     pub db_client: MongoClient,
     pub statuses: StatusVec,
     pub prefixes: PrefixMap,
-}
\ No newline at end of file
+    pub songbird: Arc<Songbird>,
+    pub ghost_ping_settings: GhostPingMap,
+    pub message_cache: MessageCache,
+}
@@ -3,21 +3,24 @@
 use std::env;
 
 use anyhow::{anyhow, Result};
-use bson::oid::ObjectId;
+use bson::{doc, oid::ObjectId};
 use chrono::{format::strftime::StrftimeItems, Utc};
-use mongodb::Collection;
+use mongodb::{options::FindOptions, Client as MongoClient, Collection};
 use rand::{seq::SliceRandom, thread_rng, Rng};
 use serenity::{
+    all::CreateMessage,
     model::{
         gateway::Activity,
-        prelude::{ChannelId, GuildId, Message},
+        prelude::{ChannelId, GuildId, Message, UserId},
         user::User,
     },
     prelude::*,
 };
 use tokio::time::{sleep, Duration};
 
-use super::types::{Handler, MessageCommandData, PrefixDoc, StatusVec};
+use crate::config::Config;
+
+use super::types::{Handler, MessageCommandData, PrefixDoc, ReminderDoc, StatusVec};
 
 /// Logs an error to the console and to the error channel.
 /// Also saves it to the database.
@@ -117,6 +120,29 @@ pub async fn parse_target_user<'a>(data: &MessageCommandData<'a>, idx: usize) ->
     Ok(user)
 }
 
+/// Returns the `index`-th (0-based) whitespace-separated word of
+/// `raw_content`, unlike `MessageCommandData.content` which is lowercased
+/// for command matching and so cannot be used for anything case-sensitive
+/// (URLs, free-text replies, ...).
+pub fn raw_word_at(raw_content: &str, index: usize) -> Option<&str> {
+    raw_content.split_whitespace().nth(index)
+}
+
+/// Returns everything in `raw_content` after skipping `skip_words`
+/// whitespace-separated words, preserving the casing and spacing that
+/// tokenizing the lowercased `MessageCommandData.content` would lose.
+pub fn raw_rest_of_message(raw_content: &str, skip_words: usize) -> String {
+    let mut rest = raw_content.trim_start();
+
+    for _ in 0..skip_words {
+        rest = rest
+            .find(char::is_whitespace)
+            .map_or("", |idx| rest[idx..].trim_start());
+    }
+
+    rest.to_string()
+}
+
 /// Registers the prefix for the guild in the database and in the prefixes map
 ///
 /// # Arguments
@@ -161,7 +187,11 @@ pub async fn start_status_loop(statuses: &StatusVec, ctx: Context) {
         let random_status = random_element_vec(&statuses.read().await);
 
         if let Some(status_doc) = random_status {
-            let activity = get_activity(&status_doc.r#type, &status_doc.status);
+            let activity = get_activity(
+                &status_doc.r#type,
+                &status_doc.status,
+                status_doc.url.as_deref(),
+            );
             ctx.set_activity(activity).await;
             debug!("Set status to: {} {}", status_doc.r#type, status_doc.status);
         } else {
@@ -172,6 +202,98 @@ pub async fn start_status_loop(statuses: &StatusVec, ctx: Context) {
     }
 }
 
+/// How long to sleep at most before re-checking the database for a newer,
+/// sooner reminder. Keeps a reminder inserted while the loop is already
+/// waiting on a far-future one from being starved behind it.
+const REMINDER_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A function that sleeps until the earliest outstanding reminder in the
+/// `reminders` collection is due, delivers it, then deletes it.
+///
+/// Re-reads the earliest reminder from the database on every iteration
+/// instead of keeping an in-memory schedule, so reminders survive a
+/// restart. While a reminder is pending, each iteration sleeps for at most
+/// `REMINDER_POLL_INTERVAL` so a newly inserted reminder with an earlier
+/// `fire_at` is picked up within that window instead of waiting out
+/// whatever reminder is currently due. When the collection is empty or the
+/// query fails, the loop waits 60 seconds before checking again.
+/// Reminders whose `fire_at` has already passed fire on the next iteration.
+pub async fn start_reminder_loop(db_client: MongoClient, ctx: Context) {
+    let reminders = db_client
+        .database("hifumi")
+        .collection::<ReminderDoc>("reminders");
+
+    loop {
+        let earliest = reminders
+            .find_one(
+                None,
+                FindOptions::builder().sort(doc! { "fire_at": 1 }).build(),
+            )
+            .await;
+
+        let reminder = match earliest {
+            Ok(Some(reminder)) => reminder,
+            Ok(None) => {
+                sleep(Duration::from_secs(60)).await;
+                continue;
+            }
+            Err(e) => {
+                error!("Failed to fetch next reminder, {e}");
+                sleep(Duration::from_secs(60)).await;
+                continue;
+            }
+        };
+
+        let wait = (reminder.fire_at - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO);
+
+        if wait > REMINDER_POLL_INTERVAL {
+            sleep(REMINDER_POLL_INTERVAL).await;
+            continue;
+        }
+        sleep(wait).await;
+
+        if let Err(e) = deliver_reminder(&ctx, &reminder).await {
+            error!("Failed to deliver reminder, {e}");
+        }
+
+        if let Err(e) = reminders
+            .delete_one(doc! { "_id": &reminder._id }, None)
+            .await
+        {
+            error!("Failed to delete delivered reminder, {e}");
+        }
+    }
+}
+
+async fn deliver_reminder(ctx: &Context, reminder: &ReminderDoc) -> Result<()> {
+    let user_id = reminder
+        .user_id
+        .parse::<u64>()
+        .map_err(|_| anyhow!("Invalid user id on reminder"))?;
+
+    let content = format!("Reminder: {}", reminder.message);
+
+    if let Ok(user) = UserId::from(user_id).to_user(&ctx.http).await {
+        let dm = user
+            .direct_message(&ctx.http, CreateMessage::new().content(&content))
+            .await;
+        if dm.is_ok() {
+            return Ok(());
+        }
+    }
+
+    let channel_id = reminder
+        .channel_id
+        .parse::<u64>()
+        .map_err(|_| anyhow!("Invalid channel id on reminder"))?;
+
+    ChannelId::from(channel_id).say(&ctx.http, content).await?;
+
+    Ok(())
+}
+
 /// Generate a random number between the given bounds
 ///
 /// # Arguments
@@ -202,6 +324,11 @@ pub fn is_indev() -> bool {
     env::var("DEV_MODE").unwrap_or_default() == "true"
 }
 
+/// Checks whether `user_id` is the primary or a secondary bot owner.
+pub fn is_bot_owner(config: &Config, user_id: UserId) -> bool {
+    user_id == config.bot_owners.primary || config.bot_owners.secondary.contains(&user_id)
+}
+
 /// Returns a random item from a slice, Some(item) if the slice is not empty,
 /// None otherwise.
 ///
@@ -232,6 +359,7 @@ pub fn random_element_vec<T: Clone>(vec: &[T]) -> Option<T> {
 /// - `LISTENING` -> `Activity::listening`
 /// - `PLAYING` -> `Activity::playing`
 /// - `COMPETING` -> `Activity::competing`
+/// - `STREAMING` -> `Activity::streaming`, falls back to `Activity::playing` if `url` is missing
 ///
 /// Returns a Discord activity based on the status type and name.
 ///
@@ -239,21 +367,24 @@ pub fn random_element_vec<T: Clone>(vec: &[T]) -> Option<T> {
 ///
 /// * `r#type` - The status type.
 /// * `status_msg` - The status message
+/// * `url` - The stream URL, required for `STREAMING` and ignored otherwise.
 ///
 /// # Examples
 ///
 /// ```
-/// let activity = get_activity("WATCHING", "Star Wars");
+/// let activity = get_activity("WATCHING", "Star Wars", None);
 /// assert_eq!(activity, Activity::watching("Star Wars"));
 ///
-/// let activity = get_activity("EATING", "Pizza");
+/// let activity = get_activity("EATING", "Pizza", None);
 /// assert_eq!(activity, Activity::playing("Pizza")
 /// ```
-pub fn get_activity(r#type: &str, status_msg: &str) -> Activity {
-    match r#type.to_lowercase().as_str() {
-        "listening" => Activity::listening(status_msg),
-        "watching"  => Activity::watching(status_msg),
-        "competing" => Activity::competing(status_msg),
+pub fn get_activity(r#type: &str, status_msg: &str, url: Option<&str>) -> Activity {
+    match (r#type.to_lowercase().as_str(), url) {
+        ("listening", _) => Activity::listening(status_msg),
+        ("watching",  _) => Activity::watching(status_msg),
+        ("competing", _) => Activity::competing(status_msg),
+        ("streaming", Some(url)) => Activity::streaming(status_msg, url)
+            .unwrap_or_else(|_| Activity::playing(status_msg)),
         _ => Activity::playing(status_msg),
     }
 }
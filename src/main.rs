@@ -15,21 +15,33 @@ use futures::stream::TryStreamExt;
 use log::{Level, LevelFilter};
 use mongodb::{options::ClientOptions, Client as MongoClient};
 use pretty_env_logger::{env_logger::fmt::Color, formatted_builder};
-use serenity::{async_trait, model::prelude::*, prelude::*, Client as DiscordClient};
+use serenity::{
+    all::MessageUpdateEvent, async_trait, model::application::Interaction, model::prelude::*,
+    prelude::*, Client as DiscordClient,
+};
+use songbird::Songbird;
 use tokio::sync::RwLock;
 
 use crate::{
     config::Config,
-    handlers::messages::handle_message,
+    handlers::{
+        interactions::{handle_interaction_create, register_commands},
+        messages::handle_message,
+        moderation::{cache_message, handle_message_delete, handle_message_update},
+    },
     helpers::{
-        types::{Handler, PrefixDoc, StatusDoc},
-        utils::{error_log, is_indev, start_status_loop},
+        types::{GhostPingDoc, Handler, PrefixDoc, StatusDoc},
+        utils::{error_log, is_indev, start_reminder_loop, start_status_loop},
     },
 };
 
 #[async_trait]
 impl EventHandler for Handler<'_> {
     async fn message(&self, ctx: Context, msg: Message) {
+        if let Err(e) = cache_message(self, &msg).await {
+            error!("Failed to cache message, {e}");
+        }
+
         match handle_message(self, &ctx, &msg).await {
             Ok(_) => (),
             Err(e) => {
@@ -45,6 +57,38 @@ impl EventHandler for Handler<'_> {
         }
     }
 
+    async fn message_delete(
+        &self,
+        ctx: Context,
+        channel_id: ChannelId,
+        deleted_message_id: MessageId,
+        guild_id: Option<GuildId>,
+    ) {
+        if let Err(e) =
+            handle_message_delete(self, &ctx, channel_id, deleted_message_id, guild_id).await
+        {
+            error!("Failed to handle message delete, {e}");
+        }
+    }
+
+    async fn message_update(
+        &self,
+        ctx: Context,
+        _old_if_available: Option<Message>,
+        _new: Option<Message>,
+        event: MessageUpdateEvent,
+    ) {
+        if let Err(e) = handle_message_update(self, &ctx, &event).await {
+            error!("Failed to handle message update, {e}");
+        }
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        if let Err(e) = handle_interaction_create(&ctx, interaction).await {
+            error!("Failed to handle interaction, {e}");
+        }
+    }
+
     async fn ready(&self, ctx: Context, ready: Ready) {
         let date_format = StrftimeItems::new("%d/%m/%Y %H:%M:%S UTC");
         let done_loading_time = Utc::now();
@@ -60,7 +104,12 @@ impl EventHandler for Handler<'_> {
         info!("{}", ready.user.id);
         info!("------------------");
 
-        let status_loop = start_status_loop(&self.statuses, ctx);
+        if let Err(e) = register_commands(&ctx).await {
+            error!("Failed to register application commands, {e}");
+        }
+
+        let status_loop = start_status_loop(&self.statuses, ctx.clone());
+        let reminder_loop = start_reminder_loop(self.db_client.clone(), ctx);
 
         if is_indev() {
             info!("Running in dev mode");
@@ -68,7 +117,7 @@ impl EventHandler for Handler<'_> {
             info!("Running in production mode");
         }
 
-        futures::join!(status_loop);
+        futures::join!(status_loop, reminder_loop);
     }
 }
 
@@ -120,6 +169,7 @@ async fn main() -> Result<()> {
         | GatewayIntents::GUILD_MESSAGES
         | GatewayIntents::GUILD_MESSAGE_REACTIONS
         | GatewayIntents::MESSAGE_CONTENT
+        | GatewayIntents::GUILD_VOICE_STATES
         | GatewayIntents::DIRECT_MESSAGES;
 
     let config = Config::new();
@@ -159,6 +209,22 @@ async fn main() -> Result<()> {
         prefixes.insert(prefix_doc.server_id, prefix_doc.prefix);
     }
 
+    let mut ghost_ping_settings: HashMap<String, bool> = HashMap::new();
+
+    let ghost_ping_array = mongo_client
+        .database("hifumi")
+        .collection::<GhostPingDoc>("ghost_ping_config")
+        .find(None, None)
+        .await?
+        .try_collect::<Vec<GhostPingDoc>>()
+        .await?;
+
+    for ghost_ping_doc in ghost_ping_array {
+        ghost_ping_settings.insert(ghost_ping_doc.server_id, ghost_ping_doc.enabled);
+    }
+
+    let songbird_manager = Songbird::serenity();
+
     let mut client = DiscordClient::builder(token, intents)
         .event_handler(Handler {
             start_time,
@@ -166,7 +232,11 @@ async fn main() -> Result<()> {
             db_client: mongo_client,
             statuses: RwLock::new(status_array),
             prefixes: RwLock::new(prefixes),
+            songbird: songbird_manager.clone(),
+            ghost_ping_settings: RwLock::new(ghost_ping_settings),
+            message_cache: RwLock::new(HashMap::new()),
         })
+        .register_songbird_with(songbird_manager)
         .await
         .unwrap_or_else(|err| {
             error!("Error creating client: {err:?}");